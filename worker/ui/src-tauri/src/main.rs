@@ -7,11 +7,21 @@
 
 use std::fs;
 use std::path::PathBuf;
+use std::process::Command;
+use std::sync::RwLock;
+use std::time::Duration;
+use argon2::Argon2;
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+use rand::rngs::OsRng;
+use rand::RngCore;
 use serde::{Deserialize, Serialize};
 use tauri::{
-    CustomMenuItem, Manager, SystemTray, SystemTrayEvent, SystemTrayMenu, 
-    SystemTrayMenuItem
+    CustomMenuItem, GlobalShortcutManager, Manager, SystemTray, SystemTrayEvent,
+    SystemTrayMenu, SystemTrayMenuItem
 };
+use tauri::api::notification::Notification;
+use tauri_plugin_autostart::ManagerExt;
 
 // ============================================================================
 // CONSTANTS
@@ -19,6 +29,16 @@ use tauri::{
 
 const WORKER_API_URL: &str = "http://127.0.0.1:8765";
 const CONFIG_FILE: &str = "worker_config.json";
+const STATUS_EVENT: &str = "worker-status-changed";
+const DEFAULT_POLL_INTERVAL_SECS: u64 = 5;
+const KILL_EVENT: &str = "kill-automation-result";
+const DEFAULT_KILL_SHORTCUT: &str = "Ctrl+Alt+K";
+const CREDENTIALS_FILE: &str = "credentials.enc";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+const AUTOSTART_WORKER_MAX_ATTEMPTS: u32 = 10;
+const AUTOSTART_WORKER_INITIAL_BACKOFF_SECS: u64 = 1;
+const AUTOSTART_WORKER_MAX_BACKOFF_SECS: u64 = 30;
 
 // ============================================================================
 // TYPES
@@ -33,17 +53,37 @@ struct WorkerStatus {
     current_execution_id: Option<String>,
     current_execution_pid: Option<u32>,
     last_heartbeat: Option<String>,
+    log_file: Option<String>,
     stats: Stats,
     config: WorkerConfig,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 struct Stats {
     executions_completed: u32,
     executions_failed: u32,
     started_at: Option<String>,
 }
 
+/// Fields of `WorkerStatus` we diff between polls to decide whether the
+/// frontend and tray actually need to be notified.
+#[derive(PartialEq)]
+struct StatusSnapshot {
+    running: bool,
+    has_active_execution: bool,
+    stats: Stats,
+}
+
+impl From<&WorkerStatus> for StatusSnapshot {
+    fn from(status: &WorkerStatus) -> Self {
+        StatusSnapshot {
+            running: status.running,
+            has_active_execution: status.has_active_execution,
+            stats: status.stats.clone(),
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 struct WorkerConfig {
     orchestrator_url: String,
@@ -59,10 +99,18 @@ struct ConfigUpdate {
     worker_name: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 struct SavedConfig {
     orchestrator_url: String,
     worker_name: String,
+    #[serde(default = "default_kill_shortcut")]
+    kill_shortcut: String,
+    #[serde(default)]
+    autostart: bool,
+}
+
+fn default_kill_shortcut() -> String {
+    DEFAULT_KILL_SHORTCUT.to_string()
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -71,24 +119,60 @@ struct KillResult {
     execution_id: Option<String>,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredCredentials {
+    api_key: String,
+    tenant_id: String,
+}
+
+// ============================================================================
+// MANAGED STATE
+// ============================================================================
+
+/// Shared across all commands via `Manager::manage` so every call reuses the
+/// same connection-pooled `reqwest::Client` instead of creating one per
+/// invocation. The worker API base URL is `RwLock`-wrapped so it can be
+/// resolved at runtime rather than baked in as a compile-time constant.
+struct AppState {
+    client: reqwest::Client,
+    worker_api_url: RwLock<String>,
+    config: RwLock<SavedConfig>,
+}
+
+impl AppState {
+    fn new() -> Self {
+        AppState {
+            client: reqwest::Client::new(),
+            worker_api_url: RwLock::new(WORKER_API_URL.to_string()),
+            config: RwLock::new(load_local_config().unwrap_or_else(|_| SavedConfig {
+                orchestrator_url: "http://localhost:8000".to_string(),
+                worker_name: "RPA-Worker-01".to_string(),
+                kill_shortcut: default_kill_shortcut(),
+                autostart: false,
+            })),
+        }
+    }
+
+    fn worker_api_url(&self) -> String {
+        self.worker_api_url.read().unwrap().clone()
+    }
+}
+
 // ============================================================================
 // TAURI COMMANDS
 // ============================================================================
 
-#[tauri::command]
-async fn get_worker_status() -> Result<WorkerStatus, String> {
-    let client = reqwest::Client::new();
-    
+async fn fetch_worker_status(client: &reqwest::Client, base_url: &str) -> Result<WorkerStatus, String> {
     let response = client
-        .get(format!("{}/status", WORKER_API_URL))
+        .get(format!("{}/status", base_url))
         .send()
         .await
         .map_err(|e| format!("Erro ao conectar ao serviço: {}", e))?;
-    
+
     if !response.status().is_success() {
         return Err(format!("Serviço retornou erro: {}", response.status()));
     }
-    
+
     response
         .json::<WorkerStatus>()
         .await
@@ -96,49 +180,51 @@ async fn get_worker_status() -> Result<WorkerStatus, String> {
 }
 
 #[tauri::command]
-async fn start_worker() -> Result<String, String> {
-    let client = reqwest::Client::new();
-    
-    let response = client
-        .post(format!("{}/start", WORKER_API_URL))
+async fn get_worker_status(state: tauri::State<'_, AppState>) -> Result<WorkerStatus, String> {
+    fetch_worker_status(&state.client, &state.worker_api_url()).await
+}
+
+#[tauri::command]
+async fn start_worker(state: tauri::State<'_, AppState>) -> Result<String, String> {
+    let response = state
+        .client
+        .post(format!("{}/start", state.worker_api_url()))
         .send()
         .await
         .map_err(|e| format!("Erro ao iniciar worker: {}", e))?;
-    
+
     if !response.status().is_success() {
         return Err(format!("Erro ao iniciar: {}", response.status()));
     }
-    
+
     Ok("Worker iniciado".to_string())
 }
 
 #[tauri::command]
-async fn stop_worker() -> Result<String, String> {
-    let client = reqwest::Client::new();
-    
-    let response = client
-        .post(format!("{}/stop", WORKER_API_URL))
+async fn stop_worker(state: tauri::State<'_, AppState>) -> Result<String, String> {
+    let response = state
+        .client
+        .post(format!("{}/stop", state.worker_api_url()))
         .send()
         .await
         .map_err(|e| format!("Erro ao parar worker: {}", e))?;
-    
+
     if !response.status().is_success() {
         return Err(format!("Erro ao parar: {}", response.status()));
     }
-    
+
     Ok("Worker parado".to_string())
 }
 
 #[tauri::command]
-async fn kill_automation() -> Result<KillResult, String> {
-    let client = reqwest::Client::new();
-    
-    let response = client
-        .post(format!("{}/execution/kill", WORKER_API_URL))
+async fn kill_automation(state: tauri::State<'_, AppState>) -> Result<KillResult, String> {
+    let response = state
+        .client
+        .post(format!("{}/execution/kill", state.worker_api_url()))
         .send()
         .await
         .map_err(|e| format!("Erro ao matar execução: {}", e))?;
-    
+
     if response.status().is_success() {
         let result = response
             .json::<KillResult>()
@@ -151,58 +237,94 @@ async fn kill_automation() -> Result<KillResult, String> {
 }
 
 #[tauri::command]
-async fn save_config(config: ConfigUpdate) -> Result<String, String> {
-    let client = reqwest::Client::new();
-    
+async fn save_config(config: ConfigUpdate, state: tauri::State<'_, AppState>) -> Result<String, String> {
     // Envia para o worker
     let worker_config = serde_json::json!({
         "orchestrator_url": config.orchestrator_url,
         "api_key": config.api_key,
         "tenant_id": config.tenant_id
     });
-    
-    let response = client
-        .post(format!("{}/config", WORKER_API_URL))
+
+    let response = state
+        .client
+        .post(format!("{}/config", state.worker_api_url()))
         .json(&worker_config)
         .send()
         .await
         .map_err(|e| format!("Erro ao salvar configuração: {}", e))?;
-    
+
     if !response.status().is_success() {
         return Err(format!("Erro ao salvar: {}", response.status()));
     }
-    
+
     // Salva localmente (sem credenciais sensíveis)
     let saved_config = SavedConfig {
         orchestrator_url: config.orchestrator_url,
         worker_name: config.worker_name,
+        kill_shortcut: state.config.read().unwrap().kill_shortcut.clone(),
+        autostart: state.config.read().unwrap().autostart,
     };
-    
+
     save_local_config(&saved_config)?;
-    
+    *state.config.write().unwrap() = saved_config;
+
     Ok("Configuração salva".to_string())
 }
 
 #[tauri::command]
-async fn get_config() -> Result<SavedConfig, String> {
-    load_local_config()
+async fn get_config(state: tauri::State<'_, AppState>) -> Result<SavedConfig, String> {
+    Ok(state.config.read().unwrap().clone())
+}
+
+/// Re-points every command at a different worker API base URL at runtime,
+/// without restarting the app. This is what makes `AppState::worker_api_url`
+/// more than the old compile-time `WORKER_API_URL` constant.
+#[tauri::command]
+async fn set_worker_api_url(url: String, state: tauri::State<'_, AppState>) -> Result<(), String> {
+    *state.worker_api_url.write().unwrap() = url;
+    Ok(())
+}
+
+#[tauri::command]
+async fn set_kill_shortcut(
+    shortcut: String,
+    app_handle: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> Result<(), String> {
+    let previous = state.config.read().unwrap().kill_shortcut.clone();
+    register_kill_shortcut(&app_handle, Some(&previous), &shortcut)?;
+
+    let saved_config = {
+        let mut config = state.config.write().unwrap();
+        config.kill_shortcut = shortcut;
+        config.clone()
+    };
+    save_local_config(&saved_config)
 }
 
 // ============================================================================
 // LOCAL CONFIG MANAGEMENT
 // ============================================================================
 
-fn get_config_path() -> Result<PathBuf, String> {
+fn app_config_dir() -> Result<PathBuf, String> {
     let config_dir = dirs::config_dir()
         .ok_or_else(|| "Não foi possível encontrar diretório de configuração".to_string())?;
-    
+
     let app_config_dir = config_dir.join("RpaWorker");
-    
+
     // Cria diretório se não existir
     fs::create_dir_all(&app_config_dir)
         .map_err(|e| format!("Erro ao criar diretório de configuração: {}", e))?;
-    
-    Ok(app_config_dir.join(CONFIG_FILE))
+
+    Ok(app_config_dir)
+}
+
+fn get_config_path() -> Result<PathBuf, String> {
+    Ok(app_config_dir()?.join(CONFIG_FILE))
+}
+
+fn get_credentials_path() -> Result<PathBuf, String> {
+    Ok(app_config_dir()?.join(CREDENTIALS_FILE))
 }
 
 fn save_local_config(config: &SavedConfig) -> Result<(), String> {
@@ -225,6 +347,8 @@ fn load_local_config() -> Result<SavedConfig, String> {
         return Ok(SavedConfig {
             orchestrator_url: "http://localhost:8000".to_string(),
             worker_name: "RPA-Worker-01".to_string(),
+            kill_shortcut: default_kill_shortcut(),
+            autostart: false,
         });
     }
     
@@ -235,6 +359,270 @@ fn load_local_config() -> Result<SavedConfig, String> {
         .map_err(|e| format!("Erro ao processar configuração: {}", e))
 }
 
+// ============================================================================
+// ENCRYPTED CREDENTIAL STORE
+// ============================================================================
+
+/// Derives a 256-bit ChaCha20-Poly1305 key from the user's master passphrase
+/// with Argon2, so the on-disk blob is only as strong as the passphrase and
+/// never stores `api_key`/`tenant_id` in plaintext.
+fn derive_key(passphrase: &str, salt: &[u8]) -> Result<[u8; 32], String> {
+    let mut key = [0u8; 32];
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| format!("Erro ao derivar chave de criptografia: {}", e))?;
+    Ok(key)
+}
+
+/// Layout written to `credentials.enc`: `salt || nonce || ciphertext`.
+fn encrypt_credentials(passphrase: &str, credentials: &StoredCredentials) -> Result<Vec<u8>, String> {
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    let key = derive_key(passphrase, &salt)?;
+    let cipher = ChaCha20Poly1305::new((&key).into());
+
+    let mut nonce_bytes = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut nonce_bytes);
+    let nonce = Nonce::from_slice(&nonce_bytes);
+
+    let plaintext = serde_json::to_vec(credentials)
+        .map_err(|e| format!("Erro ao serializar credenciais: {}", e))?;
+    let ciphertext = cipher
+        .encrypt(nonce, plaintext.as_ref())
+        .map_err(|e| format!("Erro ao criptografar credenciais: {}", e))?;
+
+    let mut blob = Vec::with_capacity(SALT_LEN + NONCE_LEN + ciphertext.len());
+    blob.extend_from_slice(&salt);
+    blob.extend_from_slice(&nonce_bytes);
+    blob.extend_from_slice(&ciphertext);
+    Ok(blob)
+}
+
+fn decrypt_credentials(passphrase: &str, blob: &[u8]) -> Result<StoredCredentials, String> {
+    if blob.len() < SALT_LEN + NONCE_LEN {
+        return Err("Arquivo de credenciais corrompido".to_string());
+    }
+
+    let (salt, rest) = blob.split_at(SALT_LEN);
+    let (nonce_bytes, ciphertext) = rest.split_at(NONCE_LEN);
+
+    let key = derive_key(passphrase, salt)?;
+    let cipher = ChaCha20Poly1305::new((&key).into());
+    let nonce = Nonce::from_slice(nonce_bytes);
+
+    let plaintext = cipher
+        .decrypt(nonce, ciphertext)
+        .map_err(|_| "Senha incorreta ou arquivo de credenciais corrompido".to_string())?;
+
+    serde_json::from_slice(&plaintext)
+        .map_err(|e| format!("Erro ao processar credenciais: {}", e))
+}
+
+#[tauri::command]
+async fn store_credentials(passphrase: String, api_key: String, tenant_id: String) -> Result<String, String> {
+    let credentials = StoredCredentials { api_key, tenant_id };
+    let blob = encrypt_credentials(&passphrase, &credentials)?;
+
+    fs::write(get_credentials_path()?, blob)
+        .map_err(|e| format!("Erro ao salvar credenciais: {}", e))?;
+
+    Ok("Credenciais armazenadas com segurança".to_string())
+}
+
+#[tauri::command]
+async fn unlock_credentials(passphrase: String, state: tauri::State<'_, AppState>) -> Result<String, String> {
+    let path = get_credentials_path()?;
+    if !path.exists() {
+        return Err("Nenhuma credencial armazenada".to_string());
+    }
+
+    let blob = fs::read(&path).map_err(|e| format!("Erro ao ler credenciais: {}", e))?;
+    let credentials = decrypt_credentials(&passphrase, &blob)?;
+
+    let orchestrator_url = state.config.read().unwrap().orchestrator_url.clone();
+    let worker_config = serde_json::json!({
+        "orchestrator_url": orchestrator_url,
+        "api_key": credentials.api_key,
+        "tenant_id": credentials.tenant_id
+    });
+
+    let response = state
+        .client
+        .post(format!("{}/config", state.worker_api_url()))
+        .json(&worker_config)
+        .send()
+        .await
+        .map_err(|e| format!("Erro ao reenviar credenciais ao worker: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("Erro ao reconfigurar worker: {}", response.status()));
+    }
+
+    Ok("Credenciais desbloqueadas e reenviadas ao worker".to_string())
+}
+
+// ============================================================================
+// LOG VIEWER
+// ============================================================================
+
+/// Quotes a value as a single POSIX shell word, so it can't break out of the
+/// surrounding command even if it contains quotes, spaces or shell metachars.
+fn shell_single_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+/// Quotes a value as an AppleScript string literal.
+fn applescript_quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Quotes a value as a PowerShell single-quoted string literal.
+fn powershell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+/// Opens `log_file` in a new terminal window, tailing it live. On Linux the
+/// tail program is spawned as its own argv (never interpolated into a shell
+/// command string). On Windows the tail runs through PowerShell's `-Command`,
+/// which parses its arguments as PowerShell text, so the path is passed as a
+/// single-quoted PowerShell literal. On macOS Terminal.app only accepts a
+/// command via AppleScript's `do script` — there the value is shell- and
+/// AppleScript-quoted so it can't inject extra commands.
+#[cfg(target_os = "windows")]
+fn spawn_log_terminal(log_file: &str) -> Result<(), String> {
+    let quoted_path = powershell_quote(log_file);
+    let tail_args = ["-NoExit", "-Command", "Get-Content", "-LiteralPath", quoted_path.as_str(), "-Wait"];
+
+    let mut cmd = if which::which("wt").is_ok() {
+        let mut wt = Command::new("wt");
+        wt.arg("powershell");
+        wt
+    } else {
+        // Sem o Windows Terminal instalado, o próprio PowerShell já abre em
+        // sua janela de console.
+        Command::new("powershell")
+    };
+    cmd.args(tail_args);
+
+    cmd.spawn()
+        .map(|_| ())
+        .map_err(|e| format!("Erro ao abrir terminal de logs: {}", e))
+}
+
+#[cfg(target_os = "macos")]
+fn spawn_log_terminal(log_file: &str) -> Result<(), String> {
+    let tail_command = format!("tail -f {}", shell_single_quote(log_file));
+    let script = format!(
+        "tell application \"Terminal\" to do script {}",
+        applescript_quote(&tail_command)
+    );
+
+    Command::new("osascript")
+        .arg("-e")
+        .arg(script)
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| format!("Erro ao abrir terminal de logs: {}", e))
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn spawn_log_terminal(log_file: &str) -> Result<(), String> {
+    for candidate in ["x-terminal-emulator", "gnome-terminal", "konsole", "xterm"] {
+        if which::which(candidate).is_err() {
+            continue;
+        }
+
+        let mut cmd = Command::new(candidate);
+        cmd.arg(if candidate == "gnome-terminal" { "--" } else { "-e" });
+        cmd.arg("tail").arg("-f").arg(log_file);
+
+        return cmd
+            .spawn()
+            .map(|_| ())
+            .map_err(|e| format!("Erro ao abrir terminal de logs: {}", e));
+    }
+
+    Err("Nenhum emulador de terminal encontrado no PATH".to_string())
+}
+
+#[tauri::command]
+async fn open_logs(state: tauri::State<'_, AppState>) -> Result<String, String> {
+    let status = fetch_worker_status(&state.client, &state.worker_api_url()).await?;
+    let log_file = status
+        .log_file
+        .ok_or_else(|| "O worker não reportou um arquivo de log".to_string())?;
+
+    spawn_log_terminal(&log_file)?;
+
+    Ok("Terminal de logs aberto".to_string())
+}
+
+// ============================================================================
+// AUTOSTART
+// ============================================================================
+
+#[tauri::command]
+async fn set_autostart(
+    enabled: bool,
+    app_handle: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> Result<(), String> {
+    let autolaunch = app_handle.autolaunch();
+    let result = if enabled { autolaunch.enable() } else { autolaunch.disable() };
+    result.map_err(|e| format!("Erro ao configurar início automático: {}", e))?;
+
+    let saved_config = {
+        let mut config = state.config.write().unwrap();
+        config.autostart = enabled;
+        config.clone()
+    };
+    save_local_config(&saved_config)?;
+
+    let _ = app_handle.tray_handle().get_item("autostart").set_selected(enabled);
+
+    Ok(())
+}
+
+/// If the user opted into autostart, retries `/status` with a capped
+/// exponential backoff until the worker service comes up, since it may still
+/// be starting right after a reboot, then starts it automatically. Gives up
+/// after `AUTOSTART_WORKER_MAX_ATTEMPTS` attempts without starting it.
+fn spawn_autostart_worker_launch(app_handle: tauri::AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let state = app_handle.state::<AppState>();
+        if !state.config.read().unwrap().autostart {
+            return;
+        }
+        let client = state.client.clone();
+        let base_url = state.worker_api_url();
+        drop(state);
+
+        let mut backoff = Duration::from_secs(AUTOSTART_WORKER_INITIAL_BACKOFF_SECS);
+        for attempt in 1..=AUTOSTART_WORKER_MAX_ATTEMPTS {
+            if client.get(format!("{}/status", base_url)).send().await.is_ok() {
+                let state = app_handle.state::<AppState>();
+                match start_worker(state).await {
+                    Ok(_) => println!("Worker iniciado automaticamente"),
+                    Err(e) => eprintln!("Erro ao iniciar worker automaticamente: {}", e),
+                }
+                return;
+            }
+
+            eprintln!(
+                "Worker indisponível (tentativa {}/{}), nova tentativa em {:?}",
+                attempt, AUTOSTART_WORKER_MAX_ATTEMPTS, backoff
+            );
+            tokio::time::sleep(backoff).await;
+            backoff = (backoff * 2).min(Duration::from_secs(AUTOSTART_WORKER_MAX_BACKOFF_SECS));
+        }
+
+        eprintln!(
+            "Worker não ficou disponível após {} tentativas; início automático cancelado",
+            AUTOSTART_WORKER_MAX_ATTEMPTS
+        );
+    });
+}
+
 // ============================================================================
 // SYSTEM TRAY
 // ============================================================================
@@ -243,33 +631,50 @@ fn create_system_tray() -> SystemTray {
     let show = CustomMenuItem::new("show".to_string(), "Abrir Painel");
     let start = CustomMenuItem::new("start".to_string(), "Iniciar Worker");
     let stop = CustomMenuItem::new("stop".to_string(), "Parar Worker");
+    let logs = CustomMenuItem::new("logs".to_string(), "Ver Logs");
+    let mut autostart = CustomMenuItem::new("autostart".to_string(), "Iniciar com o Sistema");
+    if load_local_config().map(|c| c.autostart).unwrap_or(false) {
+        autostart = autostart.selected();
+    }
     let quit = CustomMenuItem::new("quit".to_string(), "Sair");
-    
+
     let tray_menu = SystemTrayMenu::new()
         .add_item(show)
         .add_native_item(SystemTrayMenuItem::Separator)
         .add_item(start)
         .add_item(stop)
+        .add_item(logs)
+        .add_native_item(SystemTrayMenuItem::Separator)
+        .add_item(autostart)
         .add_native_item(SystemTrayMenuItem::Separator)
         .add_item(quit);
-    
+
     SystemTray::new().with_menu(tray_menu)
 }
 
+fn show_main_window(app: &tauri::AppHandle) {
+    if let Some(window) = app.get_window("main") {
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+}
+
+fn update_tray_for_status(app: &tauri::AppHandle, status: &WorkerStatus) {
+    let tray = app.tray_handle();
+    let _ = tray.get_item("start").set_enabled(!status.running);
+    let _ = tray.get_item("stop").set_enabled(status.running);
+}
+
 fn handle_system_tray_event(app: &tauri::AppHandle, event: SystemTrayEvent) {
     match event {
         SystemTrayEvent::MenuItemClick { id, .. } => {
             match id.as_str() {
-                "show" => {
-                    if let Some(window) = app.get_window("main") {
-                        let _ = window.show();
-                        let _ = window.set_focus();
-                    }
-                }
+                "show" => show_main_window(app),
                 "start" => {
                     let app_handle = app.clone();
                     tauri::async_runtime::spawn(async move {
-                        match start_worker().await {
+                        let state = app_handle.state::<AppState>();
+                        match start_worker(state).await {
                             Ok(_) => println!("Worker iniciado via tray"),
                             Err(e) => eprintln!("Erro ao iniciar worker via tray: {}", e),
                         }
@@ -278,24 +683,39 @@ fn handle_system_tray_event(app: &tauri::AppHandle, event: SystemTrayEvent) {
                 "stop" => {
                     let app_handle = app.clone();
                     tauri::async_runtime::spawn(async move {
-                        match stop_worker().await {
+                        let state = app_handle.state::<AppState>();
+                        match stop_worker(state).await {
                             Ok(_) => println!("Worker parado via tray"),
                             Err(e) => eprintln!("Erro ao parar worker via tray: {}", e),
                         }
                     });
                 }
+                "logs" => {
+                    let app_handle = app.clone();
+                    tauri::async_runtime::spawn(async move {
+                        let state = app_handle.state::<AppState>();
+                        if let Err(e) = open_logs(state).await {
+                            eprintln!("Erro ao abrir logs via tray: {}", e);
+                        }
+                    });
+                }
+                "autostart" => {
+                    let app_handle = app.clone();
+                    let enabled = !app_handle.state::<AppState>().config.read().unwrap().autostart;
+                    tauri::async_runtime::spawn(async move {
+                        let state = app_handle.state::<AppState>();
+                        if let Err(e) = set_autostart(enabled, app_handle.clone(), state).await {
+                            eprintln!("Erro ao alternar início automático via tray: {}", e);
+                        }
+                    });
+                }
                 "quit" => {
                     std::process::exit(0);
                 }
                 _ => {}
             }
         }
-        SystemTrayEvent::DoubleClick { .. } => {
-            if let Some(window) = app.get_window("main") {
-                let _ = window.show();
-                let _ = window.set_focus();
-            }
-        }
+        SystemTrayEvent::DoubleClick { .. } => show_main_window(app),
         _ => {}
     }
 }
@@ -315,24 +735,142 @@ fn handle_window_event(event: tauri::GlobalWindowEvent) {
     }
 }
 
+// ============================================================================
+// PANIC HOTKEY
+// ============================================================================
+
+fn notify_kill(app: &tauri::AppHandle, execution_id: Option<&str>) {
+    let body = match execution_id {
+        Some(id) => format!("Execução {} foi encerrada", id),
+        None => "Nenhuma execução em andamento para encerrar".to_string(),
+    };
+
+    let _ = Notification::new(&app.config().tauri.bundle.identifier)
+        .title("RPA Worker")
+        .body(body)
+        .show();
+}
+
+/// Registers the global "panic" shortcut, only tearing down `previous` (if
+/// any) once `shortcut` is confirmed to register successfully — so a bad or
+/// unparsable accelerator can't leave the operator without a working kill
+/// switch. Triggering it kills the active automation the same way the
+/// `kill_automation` command does, then notifies the UI and the tray.
+fn register_kill_shortcut(
+    app: &tauri::AppHandle,
+    previous: Option<&str>,
+    shortcut: &str,
+) -> Result<(), String> {
+    if previous == Some(shortcut) {
+        return Ok(());
+    }
+
+    let mut shortcut_manager = app.global_shortcut_manager();
+    let app_handle = app.clone();
+    shortcut_manager
+        .register(shortcut, move || {
+            let app_handle = app_handle.clone();
+            tauri::async_runtime::spawn(async move {
+                let state = app_handle.state::<AppState>();
+                match kill_automation(state).await {
+                    Ok(result) => {
+                        let _ = app_handle.emit_all(KILL_EVENT, &result);
+                        notify_kill(&app_handle, result.execution_id.as_deref());
+                    }
+                    Err(e) => eprintln!("Erro ao executar o atalho de pânico: {}", e),
+                }
+            });
+        })
+        .map_err(|e| format!("Erro ao registrar atalho global: {}", e))?;
+
+    if let Some(previous) = previous {
+        let _ = shortcut_manager.unregister(previous);
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// STATUS WATCHER
+// ============================================================================
+
+/// Polls the worker API in the background and pushes a `worker-status-changed`
+/// event to the frontend (and updates the tray) whenever the status actually
+/// changes, so the UI no longer has to poll `get_worker_status` itself.
+fn spawn_status_watcher(app_handle: tauri::AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut last_snapshot: Option<StatusSnapshot> = None;
+        let mut poll_interval = Duration::from_secs(DEFAULT_POLL_INTERVAL_SECS);
+
+        loop {
+            let state = app_handle.state::<AppState>();
+            let status_result = fetch_worker_status(&state.client, &state.worker_api_url()).await;
+            drop(state);
+
+            match status_result {
+                Ok(status) => {
+                    let snapshot = StatusSnapshot::from(&status);
+                    if last_snapshot.as_ref() != Some(&snapshot) {
+                        let _ = app_handle.emit_all(STATUS_EVENT, &status);
+                        update_tray_for_status(&app_handle, &status);
+                        last_snapshot = Some(snapshot);
+                    }
+                    poll_interval = Duration::from_secs((status.config.polling_interval as u64).max(1));
+                }
+                Err(e) => eprintln!("Erro ao consultar status do worker: {}", e),
+            }
+
+            tokio::time::sleep(poll_interval).await;
+        }
+    });
+}
+
 // ============================================================================
 // MAIN
 // ============================================================================
 
 fn main() {
     let system_tray = create_system_tray();
-    
+
     tauri::Builder::default()
+        .plugin(tauri_plugin_single_instance::init(|app, _argv, _cwd| {
+            // Uma segunda instância foi lançada: traz a janela existente para
+            // frente ao invés de deixar os dois processos disputarem a tray
+            // e a API do worker.
+            show_main_window(app);
+        }))
+        .plugin(tauri_plugin_autostart::init(
+            tauri_plugin_autostart::MacosLauncher::LaunchAgent,
+            Some(vec!["--silent"]),
+        ))
+        .manage(AppState::new())
         .system_tray(system_tray)
         .on_system_tray_event(handle_system_tray_event)
         .on_window_event(handle_window_event)
+        .setup(|app| {
+            spawn_status_watcher(app.handle());
+            spawn_autostart_worker_launch(app.handle());
+
+            let kill_shortcut = app.state::<AppState>().config.read().unwrap().kill_shortcut.clone();
+            if let Err(e) = register_kill_shortcut(&app.handle(), None, &kill_shortcut) {
+                eprintln!("Erro ao registrar atalho de pânico: {}", e);
+            }
+
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             get_worker_status,
             start_worker,
             stop_worker,
             kill_automation,
             save_config,
-            get_config
+            get_config,
+            set_worker_api_url,
+            set_kill_shortcut,
+            store_credentials,
+            unlock_credentials,
+            open_logs,
+            set_autostart
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");